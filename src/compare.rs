@@ -0,0 +1,123 @@
+//! Speedup comparison between two decoded benchmark series.
+
+use crate::criterion::{BenchKey, Estimate};
+use std::collections::BTreeMap;
+
+/// One inner-joined comparison point between a baseline and a candidate series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub key: BenchKey,
+    pub baseline: f64,
+    pub candidate: f64,
+    /// `baseline / candidate`; greater than 1 means the candidate is faster.
+    pub speedup: f64,
+    /// `baseline - candidate`.
+    pub delta: f64,
+}
+
+/// Inner-join `baseline` and `candidate` on their shared key, computing the
+/// per-point speedup (`baseline_time / candidate_time`) and absolute delta.
+/// Keys present in only one set are skipped.
+pub fn compare(baseline: &[(BenchKey, Estimate)], candidate: &[(BenchKey, Estimate)]) -> Vec<Comparison> {
+    let candidate_by_key: BTreeMap<&BenchKey, f64> = candidate
+        .iter()
+        .map(|(key, estimate)| (key, estimate.point_estimate))
+        .collect();
+
+    baseline
+        .iter()
+        .filter_map(|(key, estimate)| {
+            let candidate_time = *candidate_by_key.get(key)?;
+            let baseline_time = estimate.point_estimate;
+            Some(Comparison {
+                key: key.clone(),
+                baseline: baseline_time,
+                candidate: candidate_time,
+                speedup: baseline_time / candidate_time,
+                delta: baseline_time - candidate_time,
+            })
+        })
+        .collect()
+}
+
+/// View the speedup column of `comparisons` as plottable `(key, estimate)`
+/// points, for handing to [`crate::plot::render_chart`].
+pub fn speedup_points(comparisons: &[Comparison]) -> Vec<(BenchKey, Estimate)> {
+    comparisons
+        .iter()
+        .map(|c| {
+            (
+                c.key.clone(),
+                Estimate {
+                    point_estimate: c.speedup,
+                    lower: c.speedup,
+                    upper: c.speedup,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Render `comparisons` as a plain-text table with a header row.
+pub fn format_table(comparisons: &[Comparison]) -> String {
+    let mut table = String::from("key\tbaseline\tcandidate\tspeedup\tdelta\n");
+    for c in comparisons {
+        table.push_str(&format!(
+            "{}\t{}\t{}\t{:.4}\t{}\n",
+            c.key, c.baseline, c.candidate, c.speedup, c.delta
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(point: f64) -> Estimate {
+        Estimate {
+            point_estimate: point,
+            lower: point,
+            upper: point,
+        }
+    }
+
+    #[test]
+    fn compare_inner_joins_on_shared_keys_only() {
+        let baseline = vec![
+            (BenchKey::Int(1), estimate(10.0)),
+            (BenchKey::Int(2), estimate(20.0)),
+        ];
+        let candidate = vec![
+            (BenchKey::Int(2), estimate(10.0)),
+            (BenchKey::Int(3), estimate(30.0)),
+        ];
+
+        let comparisons = compare(&baseline, &candidate);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].key, BenchKey::Int(2));
+        assert_eq!(comparisons[0].baseline, 20.0);
+        assert_eq!(comparisons[0].candidate, 10.0);
+        assert_eq!(comparisons[0].speedup, 2.0);
+        assert_eq!(comparisons[0].delta, 10.0);
+    }
+
+    #[test]
+    fn speedup_points_carries_speedup_as_a_flat_estimate() {
+        let comparisons = vec![Comparison {
+            key: BenchKey::Int(1),
+            baseline: 20.0,
+            candidate: 10.0,
+            speedup: 2.0,
+            delta: 10.0,
+        }];
+
+        let points = speedup_points(&comparisons);
+
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].1.point_estimate, 2.0);
+        assert_eq!(points[0].1.lower, 2.0);
+        assert_eq!(points[0].1.upper, 2.0);
+    }
+}
@@ -0,0 +1,294 @@
+//! Shared logic for locating and decoding Criterion's per-benchmark `.cbor`
+//! measurement files.
+
+use regex::Regex;
+use serde::Serialize;
+use serde_cbor::Value;
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use walkdir::WalkDir;
+
+/// The x-axis key extracted from a benchmark directory name via the
+/// user-supplied `--label-pattern`.
+///
+/// Numeric labels are kept as [`BenchKey::Int`] so they sort and plot
+/// numerically; anything that doesn't parse as an integer falls back to
+/// [`BenchKey::Str`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BenchKey {
+    Int(i32),
+    Str(String),
+}
+
+impl BenchKey {
+    /// The numeric x-axis position for this key, if it has one.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            BenchKey::Int(n) => Some(*n as f64),
+            BenchKey::Str(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for BenchKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchKey::Int(n) => write!(f, "{}", n),
+            BenchKey::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialOrd for BenchKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BenchKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (BenchKey::Int(a), BenchKey::Int(b)) => a.cmp(b),
+            (BenchKey::Str(a), BenchKey::Str(b)) => a.cmp(b),
+            (BenchKey::Int(_), BenchKey::Str(_)) => Ordering::Less,
+            (BenchKey::Str(_), BenchKey::Int(_)) => Ordering::Greater,
+        }
+    }
+}
+
+/// Extract the x-axis key for a benchmark directory name using `label_pattern`,
+/// a regex that must contain a named capture group `key`.
+fn extract_key(dir_name: &str, label_pattern: &Regex) -> Result<BenchKey, Box<dyn std::error::Error>> {
+    let captures = label_pattern
+        .captures(dir_name)
+        .ok_or_else(|| format!("label pattern did not match benchmark path {:?}", dir_name))?;
+    let label = captures
+        .name("key")
+        .ok_or("label pattern must contain a named capture group `key`")?
+        .as_str();
+
+    Ok(match label.parse::<i32>() {
+        Ok(n) => BenchKey::Int(n),
+        Err(_) => BenchKey::Str(label.to_string()),
+    })
+}
+
+/// One of Criterion's reported statistics for a single benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Metric {
+    Mean,
+    Median,
+    StdDev,
+    MedianAbsDev,
+    Slope,
+}
+
+impl Metric {
+    fn field_name(self) -> &'static str {
+        match self {
+            Metric::Mean => "mean",
+            Metric::Median => "median",
+            Metric::StdDev => "std_dev",
+            Metric::MedianAbsDev => "median_abs_dev",
+            Metric::Slope => "slope",
+        }
+    }
+}
+
+/// A single Criterion estimate: its point value plus the confidence interval
+/// bounds, e.g. `estimates.median.{point_estimate,confidence_interval}`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Estimate {
+    pub point_estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+fn as_float(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+/// Pull a single named estimate (e.g. `median`) out of a decoded `estimates` map.
+fn extract_estimate(estimates: &std::collections::BTreeMap<Value, Value>, field: &str) -> Option<Estimate> {
+    let Value::Map(metric) = estimates.get(&Value::Text(field.to_string()))? else {
+        return None;
+    };
+    let point_estimate = as_float(metric.get(&Value::Text("point_estimate".to_string()))?)?;
+
+    let Value::Map(confidence_interval) = metric.get(&Value::Text("confidence_interval".to_string()))? else {
+        return None;
+    };
+    let lower = as_float(confidence_interval.get(&Value::Text("lower_bound".to_string()))?)?;
+    let upper = as_float(confidence_interval.get(&Value::Text("upper_bound".to_string()))?)?;
+
+    Some(Estimate {
+        point_estimate,
+        lower,
+        upper,
+    })
+}
+
+/// Decode every file found under `path` whose name starts with `measurement`
+/// (Criterion's `measurement_*.cbor` files), keyed by the label that
+/// `label_pattern` extracts from its containing directory name.
+///
+/// Only `metric`'s estimate is kept; entries missing that estimate are
+/// silently skipped.
+pub fn decode_cbor(
+    path: &str,
+    label_pattern: &Regex,
+    metric: Metric,
+) -> Result<Vec<(BenchKey, Estimate)>, Box<dyn std::error::Error>> {
+    let mut key_path: Vec<(BenchKey, String)> = Vec::new();
+
+    // Walk through all directories in the data folder
+    for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        let entry_path = entry.path();
+
+        let Some(file_name) = entry_path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !file_name.starts_with("measurement") {
+            continue;
+        }
+
+        let dir_name = entry_path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| format!("could not determine benchmark directory for {:?}", entry_path))?;
+
+        let key = extract_key(dir_name, label_pattern)?;
+
+        key_path.push((key, entry_path.to_str().unwrap().to_string()));
+    }
+    key_path.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // benchmark key and selected estimate
+    let mut key_estimate: Vec<(BenchKey, Estimate)> = Vec::new();
+
+    // Decode the .cbor files
+    for (key, file_path) in key_path {
+        let file = File::open(file_path)?;
+        let reader = BufReader::new(file);
+        let value: Value = serde_cbor::from_reader(reader)?;
+
+        if let Value::Map(map) = value {
+            if let Some(Value::Map(estimates)) = map.get(&Value::Text("estimates".to_string())) {
+                if let Some(estimate) = extract_estimate(estimates, metric.field_name()) {
+                    key_estimate.push((key, estimate));
+                }
+            }
+        }
+    }
+
+    Ok(key_estimate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(re: &str) -> Regex {
+        Regex::new(re).unwrap()
+    }
+
+    #[test]
+    fn extract_key_parses_numeric_label_as_int() {
+        let key = extract_key("20th fibonacci number", &pattern(r"(?P<key>\d+)th fibonacci number")).unwrap();
+        assert_eq!(key, BenchKey::Int(20));
+    }
+
+    #[test]
+    fn extract_key_falls_back_to_str_for_non_numeric_label() {
+        let key = extract_key("bench-small", &pattern(r"bench-(?P<key>\w+)")).unwrap();
+        assert_eq!(key, BenchKey::Str("small".to_string()));
+    }
+
+    #[test]
+    fn extract_key_errors_when_pattern_does_not_match() {
+        let err = extract_key("unrelated", &pattern(r"(?P<key>\d+)th fibonacci number")).unwrap_err();
+        assert!(err.to_string().contains("did not match"));
+    }
+
+    #[test]
+    fn extract_key_errors_when_pattern_has_no_key_group() {
+        let err = extract_key("20", &pattern(r"\d+")).unwrap_err();
+        assert!(err.to_string().contains("named capture group"));
+    }
+
+    fn float_map(entries: &[(&str, f64)]) -> std::collections::BTreeMap<Value, Value> {
+        entries
+            .iter()
+            .map(|(k, v)| (Value::Text(k.to_string()), Value::Float(*v)))
+            .collect()
+    }
+
+    #[test]
+    fn extract_estimate_reads_point_and_confidence_interval() {
+        let mut estimates = std::collections::BTreeMap::new();
+        let mut median = float_map(&[("point_estimate", 12.5)]);
+        median.insert(
+            Value::Text("confidence_interval".to_string()),
+            Value::Map(float_map(&[("lower_bound", 10.0), ("upper_bound", 15.0), ("confidence_level", 0.95)])),
+        );
+        estimates.insert(Value::Text("median".to_string()), Value::Map(median));
+
+        let estimate = extract_estimate(&estimates, "median").unwrap();
+        assert_eq!(
+            estimate,
+            Estimate {
+                point_estimate: 12.5,
+                lower: 10.0,
+                upper: 15.0,
+            }
+        );
+    }
+
+    #[test]
+    fn extract_estimate_is_none_when_confidence_interval_is_missing() {
+        let mut estimates = std::collections::BTreeMap::new();
+        estimates.insert(Value::Text("median".to_string()), Value::Map(float_map(&[("point_estimate", 12.5)])));
+
+        assert_eq!(extract_estimate(&estimates, "median"), None);
+    }
+
+    #[test]
+    fn extract_estimate_is_none_when_field_is_absent() {
+        let mut estimates = std::collections::BTreeMap::new();
+        let mut mean = float_map(&[("point_estimate", 1.0)]);
+        mean.insert(
+            Value::Text("confidence_interval".to_string()),
+            Value::Map(float_map(&[("lower_bound", 0.5), ("upper_bound", 1.5)])),
+        );
+        estimates.insert(Value::Text("mean".to_string()), Value::Map(mean));
+
+        // requesting "median" when only "mean" is present under the wrong field name
+        assert_eq!(extract_estimate(&estimates, "median"), None);
+    }
+
+    #[test]
+    fn bench_key_ints_sort_numerically_and_before_strs() {
+        let mut keys = vec![
+            BenchKey::Str("b".to_string()),
+            BenchKey::Int(10),
+            BenchKey::Int(2),
+            BenchKey::Str("a".to_string()),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                BenchKey::Int(2),
+                BenchKey::Int(10),
+                BenchKey::Str("a".to_string()),
+                BenchKey::Str("b".to_string()),
+            ]
+        );
+    }
+}
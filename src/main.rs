@@ -1,56 +1,266 @@
-use walkdir::WalkDir;
+mod compare;
+mod criterion;
+mod output;
+mod plot;
 
-use serde_cbor::Value;
-use std::fs::File;
-use std::io::BufReader;
+use clap::{Parser, Subcommand};
+use criterion::{decode_cbor, Metric};
+use output::Format;
+use plot::Series;
+use regex::Regex;
+use std::path::PathBuf;
 
-fn decode_cbor(path: &str) -> Result<Vec<(i32, f64)>, Box<dyn std::error::Error>> {
-    let mut number_path: Vec<(i32,String)> = Vec::new(); 
+#[derive(Parser)]
+#[command(name = "data-plotter", about = "Decode and plot Criterion benchmark results")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decode one or more Criterion output trees into plain result files.
+    Decode {
+        /// Directory containing a Criterion `data` tree (repeatable).
+        #[arg(long = "input", required = true)]
+        inputs: Vec<String>,
+
+        /// File to write the decoded results to, paired by position with `--input`.
+        #[arg(long = "output", required = true)]
+        outputs: Vec<String>,
+
+        /// Regex with a named capture group `key` applied to each benchmark's
+        /// directory name to produce its x-axis label, e.g. `(?P<key>\d+)th fibonacci number`.
+        #[arg(long = "label-pattern")]
+        label_pattern: String,
+
+        /// Which Criterion estimate to emit.
+        #[arg(long = "metric", value_enum, default_value = "median")]
+        metric: Metric,
+
+        /// Output format for each file written via `--output`.
+        #[arg(long = "format", value_enum, default_value = "python")]
+        format: Format,
+    },
+
+    /// Decode one or more Criterion output trees and render them as a chart.
+    Plot {
+        /// Directory containing a Criterion `data` tree (repeatable).
+        #[arg(long = "input", required = true)]
+        inputs: Vec<String>,
+
+        /// Legend label for each `--input`, paired by position. Defaults to the input path.
+        #[arg(long = "series-label")]
+        series_labels: Vec<String>,
+
+        /// Regex with a named capture group `key` applied to each benchmark's
+        /// directory name to produce its x-axis label.
+        #[arg(long = "label-pattern")]
+        label_pattern: String,
+
+        /// Which Criterion estimate to plot.
+        #[arg(long = "metric", value_enum, default_value = "median")]
+        metric: Metric,
+
+        /// Chart file to write; SVG unless the extension is `.png`.
+        #[arg(long = "output")]
+        output: PathBuf,
+
+        #[arg(long = "title", default_value = "Benchmark results")]
+        title: String,
+
+        #[arg(long = "x-label", default_value = "input size")]
+        x_label: String,
+
+        #[arg(long = "y-label", default_value = "time (ns)")]
+        y_label: String,
+
+        /// Plot the y-axis on a log scale, useful when timings span orders of magnitude.
+        #[arg(long = "log-y")]
+        log_y: bool,
+    },
+
+    /// Compute the per-point speedup between a baseline and a candidate benchmark tree.
+    Compare {
+        #[arg(long = "baseline-input")]
+        baseline_input: String,
+
+        #[arg(long = "candidate-input")]
+        candidate_input: String,
+
+        /// Regex with a named capture group `key` applied to each benchmark's
+        /// directory name to produce its x-axis label.
+        #[arg(long = "label-pattern")]
+        label_pattern: String,
+
+        /// Which Criterion estimate to compare.
+        #[arg(long = "metric", value_enum, default_value = "median")]
+        metric: Metric,
+
+        /// File to write the comparison table to, in addition to stdout.
+        #[arg(long = "output")]
+        output: Option<PathBuf>,
 
-    // Walk through all directories in the data folder
-    for entry in WalkDir::new(path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
+        /// Chart file to render the speedup series to.
+        #[arg(long = "plot")]
+        plot: Option<PathBuf>,
 
-        if path.to_str().unwrap().split("/").last().unwrap().starts_with("measurement") {
-            let number = path.to_str().unwrap().split("/").nth(2).unwrap().split("th").nth(0).unwrap().parse::<i32>().unwrap();
+        /// Plot the y-axis (speedup) on a log scale.
+        #[arg(long = "log-y")]
+        log_y: bool,
+    },
+}
+
+fn run_decode(
+    inputs: &[String],
+    outputs: &[String],
+    label_pattern: &str,
+    metric: Metric,
+    format: Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if inputs.len() != outputs.len() {
+        return Err(format!(
+            "expected one --output per --input ({} inputs, {} outputs)",
+            inputs.len(),
+            outputs.len()
+        )
+        .into());
+    }
+
+    let label_pattern = Regex::new(label_pattern)?;
+
+    for (input, output) in inputs.iter().zip(outputs.iter()) {
+        let key_estimate = decode_cbor(input, &label_pattern, metric)?;
+        output::write_rows(format, &key_estimate, std::path::Path::new(output))?;
+        println!("Results written to {}", output);
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_plot(
+    inputs: &[String],
+    series_labels: &[String],
+    label_pattern: &str,
+    metric: Metric,
+    output: &std::path::Path,
+    title: &str,
+    x_label: &str,
+    y_label: &str,
+    log_y: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !series_labels.is_empty() && series_labels.len() != inputs.len() {
+        return Err(format!(
+            "expected one --series-label per --input ({} inputs, {} labels)",
+            inputs.len(),
+            series_labels.len()
+        )
+        .into());
+    }
 
-            number_path.push((number, path.to_str().unwrap().to_string()));
-        }
+    let label_pattern = Regex::new(label_pattern)?;
+
+    let series = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let points = decode_cbor(input, &label_pattern, metric)?;
+            let label = series_labels.get(i).cloned().unwrap_or_else(|| input.clone());
+            Ok(Series { label, points })
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+    plot::render_chart(&series, output, title, x_label, y_label, log_y)?;
+    println!("Chart written to {}", output.display());
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_compare(
+    baseline_input: &str,
+    candidate_input: &str,
+    label_pattern: &str,
+    metric: Metric,
+    output: Option<&std::path::Path>,
+    plot_output: Option<&std::path::Path>,
+    log_y: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let label_pattern = Regex::new(label_pattern)?;
+
+    let baseline = decode_cbor(baseline_input, &label_pattern, metric)?;
+    let candidate = decode_cbor(candidate_input, &label_pattern, metric)?;
+    let comparisons = compare::compare(&baseline, &candidate);
+
+    let table = compare::format_table(&comparisons);
+    print!("{}", table);
+    if let Some(output) = output {
+        std::fs::write(output, &table)?;
     }
-    number_path.sort_by_key(|&(num, _)| num);
-
-    
-    // fibonacci number and benchmark time vector
-    let mut number_time: Vec<(i32, f64)> = Vec::new();
-
-    // Decode the .cbor files
-    for pair in number_path {
-        let file = File::open(pair.1)?;
-        let reader = BufReader::new(file);
-        let value: Value = serde_cbor::from_reader(reader)?;
-        
-        if let Value::Map(map) = value {
-            if let Some(Value::Map(estimates)) = map.get(&Value::Text("estimates".to_string())) {
-                if let Some(Value::Map(median)) = estimates.get(&Value::Text("median".to_string())) {
-                    if let Some(Value::Float(point_estimate)) = median.get(&Value::Text("point_estimate".to_string())) {
-                        number_time.push((pair.0, *point_estimate));
-                    }
-                }
-            }
-        }
+
+    if let Some(plot_output) = plot_output {
+        let series = vec![Series {
+            label: "speedup".to_string(),
+            points: compare::speedup_points(&comparisons),
+        }];
+        plot::render_chart(&series, plot_output, "Speedup", "input size", "speedup (x)", log_y)?;
+        println!("Chart written to {}", plot_output.display());
     }
 
-    Ok(number_time)
+    Ok(())
 }
 
-fn main() {
-    let path = "omc-regs-fib-hyperkzg-benchmark-results/data";
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
 
-    match decode_cbor(path) {
-        Ok(fib_time) => println!("{:?}", fib_time),
-        Err(e) => eprintln!("Error: {}", e),
+    match cli.command {
+        Command::Decode {
+            inputs,
+            outputs,
+            label_pattern,
+            metric,
+            format,
+        } => run_decode(&inputs, &outputs, &label_pattern, metric, format)?,
+        Command::Plot {
+            inputs,
+            series_labels,
+            label_pattern,
+            metric,
+            output,
+            title,
+            x_label,
+            y_label,
+            log_y,
+        } => run_plot(
+            &inputs,
+            &series_labels,
+            &label_pattern,
+            metric,
+            &output,
+            &title,
+            &x_label,
+            &y_label,
+            log_y,
+        )?,
+        Command::Compare {
+            baseline_input,
+            candidate_input,
+            label_pattern,
+            metric,
+            output,
+            plot,
+            log_y,
+        } => run_compare(
+            &baseline_input,
+            &candidate_input,
+            &label_pattern,
+            metric,
+            output.as_deref(),
+            plot.as_deref(),
+            log_y,
+        )?,
     }
-}
\ No newline at end of file
+
+    Ok(())
+}
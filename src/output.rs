@@ -0,0 +1,119 @@
+//! Structured output formats for decoded benchmark rows: the original
+//! Python-list interchange format, plus CSV and JSON.
+
+use crate::criterion::{BenchKey, Estimate};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Python,
+    Csv,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Row {
+    key: String,
+    point_estimate: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl Row {
+    fn new(key: &BenchKey, estimate: &Estimate) -> Self {
+        Row {
+            key: key.to_string(),
+            point_estimate: estimate.point_estimate,
+            lower: estimate.lower,
+            upper: estimate.upper,
+        }
+    }
+}
+
+/// Write `rows` to `path` in the given `format`.
+pub fn write_rows(
+    format: Format,
+    rows: &[(BenchKey, Estimate)],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        Format::Python => write_python(rows, path),
+        Format::Csv => write_csv(rows, path),
+        Format::Json => write_json(rows, path),
+    }
+}
+
+/// The original `[(key, point_estimate), ...]` text format consumed by `python-plotter`.
+fn write_python(rows: &[(BenchKey, Estimate)], path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut content = String::from("[");
+    for (i, (key, estimate)) in rows.iter().enumerate() {
+        if i > 0 {
+            content.push_str(", ");
+        }
+        content.push_str(&format!("({}, {})", key, estimate.point_estimate));
+    }
+    content.push(']');
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+fn write_csv(rows: &[(BenchKey, Estimate)], path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for (key, estimate) in rows {
+        writer.serialize(Row::new(key, estimate))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_json(rows: &[(BenchKey, Estimate)], path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let records: Vec<Row> = rows.iter().map(|(key, estimate)| Row::new(key, estimate)).collect();
+    let json = serde_json::to_string_pretty(&records)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_rows() -> Vec<(BenchKey, Estimate)> {
+        vec![(
+            BenchKey::Int(20),
+            Estimate {
+                point_estimate: 1.0,
+                lower: 0.9,
+                upper: 1.1,
+            },
+        )]
+    }
+
+    #[test]
+    fn write_csv_produces_one_flat_row_per_estimate() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("data-plotter-test-{}.csv", std::process::id()));
+
+        write_csv(&sample_rows(), &path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(content, "key,point_estimate,lower,upper\n20,1.0,0.9,1.1\n");
+    }
+
+    #[test]
+    fn write_json_produces_one_object_per_estimate() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("data-plotter-test-{}.json", std::process::id()));
+
+        write_json(&sample_rows(), &path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(parsed[0]["key"], "20");
+        assert_eq!(parsed[0]["point_estimate"], 1.0);
+        assert_eq!(parsed[0]["lower"], 0.9);
+        assert_eq!(parsed[0]["upper"], 1.1);
+    }
+}
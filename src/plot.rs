@@ -0,0 +1,175 @@
+//! Native chart rendering via `plotters`, so a chart no longer needs the
+//! Python-list output format and an external plotting script.
+
+use crate::criterion::{BenchKey, Estimate};
+use plotters::coord::types::RangedCoordf64;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// One labeled series to plot, e.g. a single benchmark tree's decoded results.
+pub struct Series {
+    pub label: String,
+    pub points: Vec<(BenchKey, Estimate)>,
+}
+
+const COLORS: [RGBColor; 6] = [RED, BLUE, GREEN, MAGENTA, CYAN, BLACK];
+
+/// Render `series` as a line+scatter chart to `output`. The output format
+/// (SVG or PNG) is inferred from `output`'s extension, defaulting to SVG.
+pub fn render_chart(
+    series: &[Series],
+    output: &Path,
+    title: &str,
+    x_label: &str,
+    y_label: &str,
+    log_y: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let numeric: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|s| numeric_points(&s.points))
+        .collect::<Result<_, _>>()?;
+
+    if numeric.iter().all(Vec::is_empty) {
+        return Err("no data points to plot".into());
+    }
+
+    match output.extension().and_then(|e| e.to_str()) {
+        Some("png") => {
+            let area = BitMapBackend::new(output, (1024, 768)).into_drawing_area();
+            draw(area, series, &numeric, title, x_label, y_label, log_y)
+        }
+        _ => {
+            let area = SVGBackend::new(output, (1024, 768)).into_drawing_area();
+            draw(area, series, &numeric, title, x_label, y_label, log_y)
+        }
+    }
+}
+
+fn numeric_points(points: &[(BenchKey, Estimate)]) -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>> {
+    points
+        .iter()
+        .map(|(key, estimate)| {
+            key.as_f64()
+                .map(|x| (x, estimate.point_estimate))
+                .ok_or_else(|| format!("plotting requires numeric keys, got {:?}", key).into())
+        })
+        .collect()
+}
+
+fn bounds(numeric: &[Vec<(f64, f64)>]) -> (f64, f64, f64, f64) {
+    let all = numeric.iter().flatten();
+    let x_min = all.clone().map(|(x, _)| *x).fold(f64::INFINITY, f64::min);
+    let x_max = all.clone().map(|(x, _)| *x).fold(f64::NEG_INFINITY, f64::max);
+    let y_min = all.clone().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
+    let y_max = all.map(|(_, y)| *y).fold(f64::NEG_INFINITY, f64::max);
+    (x_min, x_max, y_min, y_max)
+}
+
+fn draw<DB: DrawingBackend>(
+    area: DrawingArea<DB, plotters::coord::Shift>,
+    series: &[Series],
+    numeric: &[Vec<(f64, f64)>],
+    title: &str,
+    x_label: &str,
+    y_label: &str,
+    log_y: bool,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    area.fill(&WHITE)?;
+    let (x_min, x_max, y_min, y_max) = bounds(numeric);
+
+    if log_y {
+        let mut chart = ChartBuilder::on(&area)
+            .caption(title, ("sans-serif", 30))
+            .margin(20)
+            .x_label_area_size(40)
+            .y_label_area_size(60)
+            .build_cartesian_2d(x_min..x_max, (y_min.max(f64::MIN_POSITIVE)..y_max).log_scale())?;
+
+        chart.configure_mesh().x_desc(x_label).y_desc(y_label).draw()?;
+        plot_series(&mut chart, series, numeric)?;
+    } else {
+        let mut chart = ChartBuilder::on(&area)
+            .caption(title, ("sans-serif", 30))
+            .margin(20)
+            .x_label_area_size(40)
+            .y_label_area_size(60)
+            .build_cartesian_2d(x_min..x_max, y_min..y_max)?;
+
+        chart.configure_mesh().x_desc(x_label).y_desc(y_label).draw()?;
+        plot_series(&mut chart, series, numeric)?;
+    }
+
+    area.present()?;
+    Ok(())
+}
+
+fn plot_series<'a, DB, CT>(
+    chart: &mut ChartContext<'a, DB, Cartesian2d<RangedCoordf64, CT>>,
+    series: &[Series],
+    numeric: &[Vec<(f64, f64)>],
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+    CT: plotters::coord::ranged1d::Ranged<ValueType = f64>,
+{
+    for (i, (s, points)) in series.iter().zip(numeric.iter()).enumerate() {
+        let color = COLORS[i % COLORS.len()];
+
+        chart
+            .draw_series(LineSeries::new(points.iter().copied(), &color))?
+            .label(&s.label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        chart.draw_series(points.iter().map(|&(x, y)| Circle::new((x, y), 3, color.filled())))?;
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(point_estimate: f64) -> Estimate {
+        Estimate {
+            point_estimate,
+            lower: point_estimate,
+            upper: point_estimate,
+        }
+    }
+
+    #[test]
+    fn numeric_points_errs_on_non_numeric_key() {
+        let points = vec![(BenchKey::Str("small".to_string()), estimate(1.0))];
+        let err = numeric_points(&points).unwrap_err();
+        assert!(err.to_string().contains("numeric keys"));
+    }
+
+    #[test]
+    fn render_chart_errs_on_all_empty_series() {
+        let series = vec![
+            Series {
+                label: "a".to_string(),
+                points: Vec::new(),
+            },
+            Series {
+                label: "b".to_string(),
+                points: Vec::new(),
+            },
+        ];
+        let dir = std::env::temp_dir();
+        let output = dir.join(format!("data-plotter-test-{}.svg", std::process::id()));
+
+        let err = render_chart(&series, &output, "title", "x", "y", false).unwrap_err();
+        assert!(err.to_string().contains("no data points"));
+    }
+}